@@ -1,4 +1,5 @@
 mod server;
+mod strategy;
 
 use server::{Position, Tank, Team};
 
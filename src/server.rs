@@ -1,13 +1,25 @@
 use std::fmt::Display;
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
 pub enum Team {
     Red,
     Blue,
     Green,
 }
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+impl Team {
+    pub fn glyph(self) -> char {
+        match self {
+            Team::Red => 'R',
+            Team::Blue => 'B',
+            Team::Green => 'G',
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
 pub struct Position {
     pub x: u32,
     pub y: u32,
@@ -19,7 +31,7 @@ impl Display for Position {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Direction {
     Up,
     Down,
@@ -33,7 +45,152 @@ impl Display for Direction {
     }
 }
 
-#[derive(Debug, Clone)]
+impl std::str::FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "up" => Ok(Direction::Up),
+            "down" => Ok(Direction::Down),
+            "left" => Ok(Direction::Left),
+            "right" => Ok(Direction::Right),
+            other => Err(format!("Unknown direction: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    Move { tank: u32, direction: Direction },
+    Shoot { attacker: u32, target: u32 },
+    Gift { from: u32, to: u32, amount: u8 },
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Move { tank, direction } => write!(f, "move {} {}", tank, direction),
+            Action::Shoot { attacker, target } => write!(f, "shoot {} {}", attacker, target),
+            Action::Gift { from, to, amount } => write!(f, "gift {} {} {}", from, to, amount),
+        }
+    }
+}
+
+impl std::str::FromStr for Action {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let verb = parts.next().ok_or_else(|| String::from("Empty action"))?;
+        match verb.to_lowercase().as_str() {
+            "move" => {
+                let tank = parts
+                    .next()
+                    .ok_or_else(|| String::from("Missing tank id"))?
+                    .parse::<u32>()
+                    .map_err(|_| String::from("Invalid tank id"))?;
+                let direction = parts
+                    .next()
+                    .ok_or_else(|| String::from("Missing direction"))?
+                    .parse::<Direction>()?;
+                Ok(Action::Move { tank, direction })
+            }
+            "shoot" => {
+                let attacker = parts
+                    .next()
+                    .ok_or_else(|| String::from("Missing attacker id"))?
+                    .parse::<u32>()
+                    .map_err(|_| String::from("Invalid attacker id"))?;
+                let target = parts
+                    .next()
+                    .ok_or_else(|| String::from("Missing target id"))?
+                    .parse::<u32>()
+                    .map_err(|_| String::from("Invalid target id"))?;
+                Ok(Action::Shoot { attacker, target })
+            }
+            "gift" | "give" => {
+                let from = parts
+                    .next()
+                    .ok_or_else(|| String::from("Missing donor id"))?
+                    .parse::<u32>()
+                    .map_err(|_| String::from("Invalid donor id"))?;
+                let to = parts
+                    .next()
+                    .ok_or_else(|| String::from("Missing recipient id"))?
+                    .parse::<u32>()
+                    .map_err(|_| String::from("Invalid recipient id"))?;
+                let amount = parts
+                    .next()
+                    .ok_or_else(|| String::from("Missing amount"))?
+                    .parse::<u8>()
+                    .map_err(|_| String::from("Invalid amount"))?;
+                Ok(Action::Gift { from, to, amount })
+            }
+            other => Err(format!("Unknown action: {}", other)),
+        }
+    }
+}
+
+impl Action {
+    /// Parses the short chat form of a command ("move up", "shoot 3 4", "give 5 2"),
+    /// where move/gift omit the acting tank's id in favor of `actor`.
+    pub fn from_chat(s: &str, actor: u32) -> Result<Self, String> {
+        let mut parts = s.split_whitespace();
+        let verb = parts.next().ok_or_else(|| String::from("Empty action"))?;
+        match verb.to_lowercase().as_str() {
+            "move" => {
+                let direction = parts
+                    .next()
+                    .ok_or_else(|| String::from("Missing direction"))?
+                    .parse::<Direction>()?;
+                Ok(Action::Move {
+                    tank: actor,
+                    direction,
+                })
+            }
+            "shoot" => {
+                let attacker = parts
+                    .next()
+                    .ok_or_else(|| String::from("Missing attacker id"))?
+                    .parse::<u32>()
+                    .map_err(|_| String::from("Invalid attacker id"))?;
+                let target = parts
+                    .next()
+                    .ok_or_else(|| String::from("Missing target id"))?
+                    .parse::<u32>()
+                    .map_err(|_| String::from("Invalid target id"))?;
+                Ok(Action::Shoot { attacker, target })
+            }
+            "gift" | "give" => {
+                let to = parts
+                    .next()
+                    .ok_or_else(|| String::from("Missing recipient id"))?
+                    .parse::<u32>()
+                    .map_err(|_| String::from("Invalid recipient id"))?;
+                let amount = parts
+                    .next()
+                    .ok_or_else(|| String::from("Missing amount"))?
+                    .parse::<u8>()
+                    .map_err(|_| String::from("Invalid amount"))?;
+                Ok(Action::Gift {
+                    from: actor,
+                    to,
+                    amount,
+                })
+            }
+            other => Err(format!("Unknown action: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActionResult {
+    Moved(Position),
+    Shot(ShotOutcome),
+    Gifted,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tank {
     pub life: u8,
     pub action_points: u8,
@@ -42,16 +199,95 @@ pub struct Tank {
     pub position: Position,
     pub name: String,
     pub id: u32,
+    pub destroyed: bool,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShotOutcome {
+    Hit,
+    Killed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Board {
-    pub length: u32,
-    pub height: u32,
+    length: u32,
+    height: u32,
+    cells: Vec<Option<u32>>,
+}
+
+impl Board {
+    pub fn new(length: u32, height: u32) -> Self {
+        Self {
+            length,
+            height,
+            cells: vec![None; (length * height) as usize],
+        }
+    }
+
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn index(&self, x: u32, y: u32) -> Result<usize, String> {
+        if x >= self.length || y >= self.height {
+            return Err(format!("Position {},{} is out of bounds", x, y));
+        }
+        Ok((y * self.length + x) as usize)
+    }
+
+    pub fn tank_at(&self, position: Position) -> Option<u32> {
+        self.index(position.x, position.y)
+            .ok()
+            .and_then(|i| self.cells[i])
+    }
+
+    fn set_cell(&mut self, position: Position, tank_id: Option<u32>) -> Result<(), String> {
+        let i = self.index(position.x, position.y)?;
+        self.cells[i] = tank_id;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GameState {
+    Lobby,
+    InProgress,
+    Finished { winner: Team },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Settings {
+    pub ap_per_tick: u8,
+    pub tick_interval_secs: i64,
 }
 
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            ap_per_tick: 1,
+            tick_interval_secs: 86_400,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TickReport {
+    pub ticks_applied: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Game {
+    id: u64,
     board: Board,
     tanks: Vec<Tank>,
+    state: GameState,
+    settings: Settings,
+    created_at: i64,
+    last_tick: i64,
 }
 
 impl Tank {
@@ -72,21 +308,101 @@ impl Tank {
             position,
             name,
             id,
+            destroyed: false,
         }
     }
 }
 
 impl Game {
+    pub fn new_lobby(id: u64, board: Board, created_at: i64) -> Self {
+        Self {
+            id,
+            board,
+            tanks: Vec::new(),
+            state: GameState::Lobby,
+            settings: Settings::default(),
+            created_at,
+            last_tick: created_at,
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("Game always serializes")
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        serde_json::from_slice(bytes).map_err(|e| e.to_string())
+    }
+
+    pub fn add_tank(&mut self, tank: Tank) -> Result<(), String> {
+        if self.state != GameState::Lobby {
+            return Err(String::from("Tanks may only join while the game is in the lobby"));
+        }
+        if self.tanks.iter().any(|x| x.id == tank.id) {
+            return Err(format!("Tank id {} is already taken", tank.id));
+        }
+        if self.board.tank_at(tank.position).is_some() {
+            return Err(format!("{} is already occupied", tank.position));
+        }
+        self.board.set_cell(tank.position, Some(tank.id))?;
+        self.tanks.push(tank);
+        Ok(())
+    }
+
+    pub fn start(&mut self) -> Result<(), String> {
+        if self.state != GameState::Lobby {
+            return Err(String::from("Game has already started"));
+        }
+        if self.tanks.len() < 2 {
+            return Err(String::from("At least two tanks are required to start"));
+        }
+        let first_team = self.tanks[0].team;
+        if self.tanks.iter().all(|x| x.team == first_team) {
+            return Err(String::from("At least two teams are required to start"));
+        }
+        self.state = GameState::InProgress;
+        Ok(())
+    }
+
+    pub fn check_winner(&mut self) -> Option<Team> {
+        let mut living_teams = self
+            .tanks
+            .iter()
+            .filter(|x| !x.destroyed)
+            .map(|x| x.team);
+        let first = living_teams.next()?;
+        if living_teams.all(|team| team == first) {
+            self.state = GameState::Finished { winner: first };
+            Some(first)
+        } else {
+            None
+        }
+    }
+
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+
     pub fn make_move(&mut self, tank_id: u32, direction: Direction) -> Result<Position, String> {
-        let tanks = self.tanks.clone();
+        if self.state != GameState::InProgress {
+            return Err(String::from("Game is not in progress"));
+        }
         let tank_option = self.tanks.iter_mut().find(|x| x.id == tank_id);
         if let Some(tank) = tank_option {
+            if tank.destroyed {
+                return Err(format!("{} is destroyed and cannot act", tank.name));
+            }
+            let origin = tank.position;
             let err = Err(format!(
                 "Illegal to move {} from {}",
                 direction, tank.position
             ));
             let dest_position = match direction {
-                Direction::Right => match tank.position.x + 1 < self.board.length {
+                Direction::Right => match tank.position.x + 1 < self.board.length() {
                     true => Position {
                         x: tank.position.x + 1,
                         y: tank.position.y,
@@ -100,7 +416,7 @@ impl Game {
                     },
                     None => return err,
                 },
-                Direction::Down => match tank.position.y + 1 < self.board.height {
+                Direction::Down => match tank.position.y + 1 < self.board.height() {
                     true => Position {
                         x: tank.position.x,
                         y: tank.position.y + 1,
@@ -115,12 +431,14 @@ impl Game {
                     None => return err,
                 },
             };
-            if let Some(_) = tanks.iter().find(|x| x.position == dest_position) {
+            if self.board.tank_at(dest_position).is_some() {
                 return err;
             } else {
                 tank.position = dest_position;
             }
-            return Ok(tank.position);
+            let _ = self.board.set_cell(origin, None);
+            let _ = self.board.set_cell(dest_position, Some(tank_id));
+            return Ok(dest_position);
         } else {
             return Err(String::from("Tank id not found"));
         }
@@ -130,22 +448,219 @@ impl Game {
         self.tanks.iter().find(|x| x.id == tank_id)
     }
 
+    pub fn tanks(&self) -> &[Tank] {
+        &self.tanks
+    }
+
     pub fn get_tank_mut(&mut self, tank_id: u32) -> Option<&mut Tank> {
         self.tanks.iter_mut().find(|x| x.id == tank_id)
     }
 
     pub fn set_tank_position(&mut self, tank_id: u32, position: Position) {
-        let l = self.board.length;
-        let h = self.board.height;
-        match self.get_tank_mut(tank_id) {
+        let l = self.board.length();
+        let h = self.board.height();
+        match self.tanks.iter_mut().find(|x| x.id == tank_id) {
             Some(tank) => {
                 if position.x < l && position.y < h {
+                    let origin = tank.position;
                     tank.position = position;
+                    let _ = self.board.set_cell(origin, None);
+                    let _ = self.board.set_cell(position, Some(tank_id));
                 }
             }
             None => todo!(),
         }
     }
+
+    pub fn shoot(&mut self, attacker_id: u32, target_id: u32) -> Result<ShotOutcome, String> {
+        if self.state != GameState::InProgress {
+            return Err(String::from("Game is not in progress"));
+        }
+        if attacker_id == target_id {
+            return Err(String::from("A tank cannot target itself"));
+        }
+
+        let attacker = self
+            .get_tank(attacker_id)
+            .ok_or_else(|| String::from("Tank id not found"))?;
+        let target = self
+            .get_tank(target_id)
+            .ok_or_else(|| String::from("Tank id not found"))?;
+
+        if attacker.destroyed {
+            return Err(format!("{} is destroyed and cannot act", attacker.name));
+        }
+        if target.destroyed {
+            return Err(format!("{} is already destroyed", target.name));
+        }
+        if attacker.team == target.team {
+            return Err(format!("{} cannot fire on its own team", attacker.name));
+        }
+        if attacker.action_points < 1 {
+            return Err(format!("{} has no action points remaining", attacker.name));
+        }
+
+        let dx = attacker.position.x.abs_diff(target.position.x);
+        let dy = attacker.position.y.abs_diff(target.position.y);
+        let distance = dx.max(dy);
+        if distance > attacker.range as u32 {
+            return Err(format!(
+                "{} is out of range of {}",
+                target.position, attacker.position
+            ));
+        }
+
+        let (outcome, spoils, target_position) = {
+            let target = self.get_tank_mut(target_id).unwrap();
+            target.life = target.life.saturating_sub(1);
+            let outcome = if target.life == 0 {
+                target.destroyed = true;
+                ShotOutcome::Killed
+            } else {
+                ShotOutcome::Hit
+            };
+            let spoils = if outcome == ShotOutcome::Killed {
+                target.action_points
+            } else {
+                0
+            };
+            if outcome == ShotOutcome::Killed {
+                target.action_points = 0;
+            }
+            (outcome, spoils, target.position)
+        };
+
+        let attacker = self.get_tank_mut(attacker_id).unwrap();
+        attacker.action_points -= 1;
+        if outcome == ShotOutcome::Killed {
+            attacker.action_points = attacker.action_points.saturating_add(spoils);
+            attacker.range = attacker.range.saturating_add(1);
+        }
+
+        if outcome == ShotOutcome::Killed {
+            let _ = self.board.set_cell(target_position, None);
+        }
+
+        Ok(outcome)
+    }
+
+    pub fn render(&self) -> String {
+        let border = format!("+{}+\n", "-".repeat(self.board.length() as usize));
+
+        let mut out = String::new();
+        out.push_str(&border);
+        for y in 0..self.board.height() {
+            out.push('|');
+            for x in 0..self.board.length() {
+                let glyph = self
+                    .board
+                    .tank_at(Position { x, y })
+                    .and_then(|id| self.get_tank(id))
+                    .map(|tank| tank.team.glyph())
+                    .unwrap_or('.');
+                out.push(glyph);
+            }
+            out.push_str("|\n");
+        }
+        out.push_str(&border);
+
+        out.push('\n');
+        for tank in &self.tanks {
+            out.push_str(&format!(
+                "{} {} ({:?}) - life: {} ap: {}\n",
+                tank.team.glyph(),
+                tank.name,
+                tank.team,
+                tank.life,
+                tank.action_points
+            ));
+        }
+
+        out
+    }
+
+    pub fn apply(&mut self, action: Action) -> Result<ActionResult, String> {
+        match action {
+            Action::Move { tank, direction } => {
+                self.make_move(tank, direction).map(ActionResult::Moved)
+            }
+            Action::Shoot { attacker, target } => {
+                self.shoot(attacker, target).map(ActionResult::Shot)
+            }
+            Action::Gift { from, to, amount } => {
+                self.gift(from, to, amount).map(|_| ActionResult::Gifted)
+            }
+        }
+    }
+
+    /// Parses and applies a chat command on behalf of `actor`, so the bot layer
+    /// never has to know the engine's action types.
+    pub fn apply_chat(&mut self, input: &str, actor: u32) -> Result<ActionResult, String> {
+        self.apply(Action::from_chat(input, actor)?)
+    }
+
+    pub fn gift(&mut self, from_id: u32, to_id: u32, amount: u8) -> Result<(), String> {
+        if self.state != GameState::InProgress {
+            return Err(String::from("Game is not in progress"));
+        }
+
+        let donor = self
+            .get_tank(from_id)
+            .ok_or_else(|| String::from("Tank id not found"))?;
+        if donor.destroyed {
+            return Err(format!("{} is destroyed", donor.name));
+        }
+        if donor.action_points < amount {
+            return Err(format!("{} does not have enough action points", donor.name));
+        }
+
+        let recipient = self
+            .get_tank(to_id)
+            .ok_or_else(|| String::from("Tank id not found"))?;
+        if recipient.destroyed {
+            return Err(format!("{} is destroyed", recipient.name));
+        }
+
+        let dx = donor.position.x.abs_diff(recipient.position.x);
+        let dy = donor.position.y.abs_diff(recipient.position.y);
+        if dx.max(dy) > donor.range as u32 {
+            return Err(format!("{} is out of range of {}", recipient.name, donor.name));
+        }
+
+        self.get_tank_mut(from_id).unwrap().action_points -= amount;
+        let recipient = self.get_tank_mut(to_id).unwrap();
+        recipient.action_points = recipient.action_points.saturating_add(amount);
+
+        Ok(())
+    }
+
+    pub fn best_action(&self, tank_id: u32, time_budget: std::time::Duration) -> Option<Action> {
+        crate::strategy::Mcts::search(self, tank_id, time_budget)
+    }
+
+    pub fn tick(&mut self, now: i64) -> TickReport {
+        let elapsed = now - self.last_tick;
+        let interval = self.settings.tick_interval_secs;
+        let ticks_applied = if interval > 0 && elapsed >= interval {
+            (elapsed / interval) as u32
+        } else {
+            0
+        };
+
+        if ticks_applied > 0 {
+            let grant = self.settings.ap_per_tick.saturating_mul(ticks_applied.min(u32::from(u8::MAX)) as u8);
+            for tank in self.tanks.iter_mut().filter(|tank| !tank.destroyed) {
+                tank.action_points = tank.action_points.saturating_add(grant);
+            }
+            self.last_tick += interval * ticks_applied as i64;
+        }
+
+        TickReport { ticks_applied }
+    }
+
+    pub fn is_stale(&self, now: i64, max_idle_secs: i64) -> bool {
+        now - self.last_tick >= max_idle_secs
+    }
 }
 
 #[cfg(test)]
@@ -174,10 +689,7 @@ pub mod tests {
 
     #[test]
     fn tank_make_move_legal_sets_position() {
-        let board = Board {
-            length: 8,
-            height: 8,
-        };
+        let mut board = Board::new(8, 8);
         let tank = Tank::new(
             3,
             1,
@@ -187,10 +699,16 @@ pub mod tests {
             String::from("Test"),
             1,
         );
+        board.set_cell(tank.position, Some(tank.id)).unwrap();
 
         let mut game = Game {
+            id: 1,
             board,
             tanks: vec![tank],
+            state: GameState::InProgress,
+            settings: Settings::default(),
+            created_at: 0,
+            last_tick: 0,
         };
 
         let position = game.make_move(1, Direction::Right).unwrap();
@@ -212,11 +730,8 @@ pub mod tests {
 
     #[test]
     fn tank_make_move_illegal_board_edge_expects_error() {
-        let board = Board {
-            length: 8,
-            height: 8,
-        };
-        let mut tank = Tank::new(
+        let mut board = Board::new(8, 8);
+        let tank = Tank::new(
             3,
             1,
             Team::Blue,
@@ -225,10 +740,16 @@ pub mod tests {
             String::from("Test"),
             1,
         );
+        board.set_cell(tank.position, Some(tank.id)).unwrap();
 
         let mut game = Game {
+            id: 1,
             board,
             tanks: vec![tank],
+            state: GameState::InProgress,
+            settings: Settings::default(),
+            created_at: 0,
+            last_tick: 0,
         };
 
         game.make_move(1, Direction::Left)
@@ -253,10 +774,7 @@ pub mod tests {
 
     #[test]
     fn tank_make_move_illegal_tank_collision_expects_error() {
-        let board = Board {
-            length: 8,
-            height: 8,
-        };
+        let mut board = Board::new(8, 8);
 
         let tank1 = Tank::new(
             3,
@@ -278,11 +796,563 @@ pub mod tests {
             2,
         );
 
+        board.set_cell(tank1.position, Some(tank1.id)).unwrap();
+        board.set_cell(tank2.position, Some(tank2.id)).unwrap();
+
         let mut game = Game {
+            id: 1,
             board,
             tanks: vec![tank1, tank2],
+            state: GameState::InProgress,
+            settings: Settings::default(),
+            created_at: 0,
+            last_tick: 0,
         };
 
         game.make_move(1, Direction::Down).expect_err("");
     }
+
+    #[test]
+    fn make_move_with_a_destroyed_tank_expects_error() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.get_tank_mut(1).unwrap().destroyed = true;
+
+        game.make_move(1, Direction::Right)
+            .expect_err("A destroyed tank should not be able to move");
+        assert_eq!(game.get_tank(1).unwrap().position, Position { x: 0, y: 0 });
+    }
+
+    fn shooter_and_target(
+        attacker_range: u8,
+        attacker_position: Position,
+        target_position: Position,
+    ) -> Game {
+        let mut board = Board::new(8, 8);
+
+        let attacker = Tank::new(
+            3,
+            1,
+            Team::Blue,
+            attacker_range,
+            attacker_position,
+            String::from("Attacker"),
+            1,
+        );
+
+        let target = Tank::new(
+            1,
+            2,
+            Team::Red,
+            2,
+            target_position,
+            String::from("Target"),
+            2,
+        );
+
+        board.set_cell(attacker.position, Some(attacker.id)).unwrap();
+        board.set_cell(target.position, Some(target.id)).unwrap();
+
+        Game {
+            id: 1,
+            board,
+            tanks: vec![attacker, target],
+            state: GameState::InProgress,
+            settings: Settings::default(),
+            created_at: 0,
+            last_tick: 0,
+        }
+    }
+
+    #[test]
+    fn shoot_out_of_range_expects_error() {
+        let mut game = shooter_and_target(1, Position { x: 0, y: 0 }, Position { x: 3, y: 3 });
+
+        game.shoot(1, 2)
+            .expect_err("Target out of range should be rejected");
+        assert_eq!(game.get_tank(2).unwrap().life, 1);
+    }
+
+    #[test]
+    fn shoot_without_action_points_expects_error() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.get_tank_mut(1).unwrap().action_points = 0;
+
+        game.shoot(1, 2)
+            .expect_err("Shooting without action points should be rejected");
+        assert_eq!(game.get_tank(2).unwrap().life, 1);
+    }
+
+    #[test]
+    fn shoot_friendly_fire_expects_error() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.get_tank_mut(2).unwrap().team = Team::Blue;
+
+        game.shoot(1, 2)
+            .expect_err("Shooting a teammate should be rejected");
+        assert_eq!(game.get_tank(2).unwrap().life, 1);
+    }
+
+    #[test]
+    fn shoot_hit_reduces_life_and_spends_action_point() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.get_tank_mut(2).unwrap().life = 2;
+
+        let outcome = game.shoot(1, 2).unwrap();
+        assert_eq!(outcome, ShotOutcome::Hit);
+        assert_eq!(game.get_tank(2).unwrap().life, 1);
+        assert_eq!(game.get_tank(1).unwrap().action_points, 0);
+        assert!(!game.get_tank(2).unwrap().destroyed);
+    }
+
+    #[test]
+    fn shoot_kill_absorbs_action_points_and_grows_range() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.get_tank_mut(2).unwrap().action_points = 4;
+
+        let outcome = game.shoot(1, 2).unwrap();
+        assert_eq!(outcome, ShotOutcome::Killed);
+
+        let target = game.get_tank(2).unwrap();
+        assert_eq!(target.life, 0);
+        assert!(target.destroyed);
+
+        let attacker = game.get_tank(1).unwrap();
+        assert_eq!(attacker.action_points, 4);
+        assert_eq!(attacker.range, 4);
+    }
+
+    #[test]
+    fn shoot_an_already_destroyed_target_expects_error() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.get_tank_mut(2).unwrap().action_points = 4;
+
+        let outcome = game.shoot(1, 2).unwrap();
+        assert_eq!(outcome, ShotOutcome::Killed);
+        assert_eq!(game.get_tank(1).unwrap().action_points, 4);
+        assert_eq!(game.get_tank(1).unwrap().range, 4);
+
+        game.shoot(1, 2)
+            .expect_err("Shooting an already-destroyed tank should be rejected");
+        assert_eq!(game.get_tank(1).unwrap().action_points, 4);
+        assert_eq!(game.get_tank(1).unwrap().range, 4);
+    }
+
+    #[test]
+    fn shoot_with_a_destroyed_attacker_expects_error() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.get_tank_mut(1).unwrap().destroyed = true;
+
+        game.shoot(1, 2)
+            .expect_err("A destroyed tank should not be able to act");
+        assert_eq!(game.get_tank(2).unwrap().life, 1);
+    }
+
+    #[test]
+    fn add_tank_outside_lobby_expects_error() {
+        let board = Board::new(8, 8);
+        let mut game = Game::new_lobby(1, board, 0);
+        game.add_tank(Tank::new(
+            3,
+            1,
+            Team::Blue,
+            2,
+            Position { x: 0, y: 0 },
+            String::from("Red"),
+            1,
+        ))
+        .unwrap();
+        game.add_tank(Tank::new(
+            3,
+            1,
+            Team::Red,
+            2,
+            Position { x: 1, y: 0 },
+            String::from("Blue"),
+            2,
+        ))
+        .unwrap();
+        game.start().unwrap();
+
+        game.add_tank(Tank::new(
+            3,
+            1,
+            Team::Green,
+            2,
+            Position { x: 2, y: 0 },
+            String::from("Green"),
+            3,
+        ))
+        .expect_err("Tanks cannot join once the game has started");
+    }
+
+    #[test]
+    fn add_tank_to_an_occupied_position_expects_error() {
+        let board = Board::new(8, 8);
+        let mut game = Game::new_lobby(1, board, 0);
+        game.add_tank(Tank::new(
+            3,
+            1,
+            Team::Blue,
+            2,
+            Position { x: 0, y: 0 },
+            String::from("Red"),
+            1,
+        ))
+        .unwrap();
+
+        game.add_tank(Tank::new(
+            3,
+            1,
+            Team::Red,
+            2,
+            Position { x: 0, y: 0 },
+            String::from("Blue"),
+            2,
+        ))
+        .expect_err("Tanks cannot join on top of an already-occupied square");
+
+        assert_eq!(game.board.tank_at(Position { x: 0, y: 0 }), Some(1));
+        assert_eq!(game.tanks().len(), 1);
+    }
+
+    #[test]
+    fn start_without_two_teams_expects_error() {
+        let board = Board::new(8, 8);
+        let mut game = Game::new_lobby(1, board, 0);
+        game.add_tank(Tank::new(
+            3,
+            1,
+            Team::Blue,
+            2,
+            Position { x: 0, y: 0 },
+            String::from("Red"),
+            1,
+        ))
+        .unwrap();
+        game.add_tank(Tank::new(
+            3,
+            1,
+            Team::Blue,
+            2,
+            Position { x: 1, y: 0 },
+            String::from("Blue"),
+            2,
+        ))
+        .unwrap();
+
+        game.start()
+            .expect_err("A single-team lobby cannot start");
+    }
+
+    #[test]
+    fn make_move_outside_lobby_start_expects_error() {
+        let board = Board::new(8, 8);
+        let mut game = Game::new_lobby(1, board, 0);
+        game.add_tank(Tank::new(
+            3,
+            1,
+            Team::Blue,
+            2,
+            Position { x: 0, y: 0 },
+            String::from("Red"),
+            1,
+        ))
+        .unwrap();
+
+        game.make_move(1, Direction::Right)
+            .expect_err("Tanks cannot move before the game starts");
+    }
+
+    #[test]
+    fn check_winner_declares_last_team_standing() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        assert_eq!(game.check_winner(), None);
+
+        game.get_tank_mut(2).unwrap().destroyed = true;
+
+        assert_eq!(game.check_winner(), Some(Team::Blue));
+        assert_eq!(*game.state(), GameState::Finished { winner: Team::Blue });
+
+        game.shoot(1, 2)
+            .expect_err("A finished game rejects further actions");
+    }
+
+    #[test]
+    fn game_round_trips_through_bytes() {
+        let game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+
+        let bytes = game.to_bytes();
+        let restored = Game::from_bytes(&bytes).unwrap();
+
+        assert_eq!(game, restored);
+    }
+
+    #[test]
+    fn board_index_computes_row_major_offset_and_bounds_checks() {
+        let board = Board::new(4, 3);
+
+        assert_eq!(board.index(0, 0).unwrap(), 0);
+        assert_eq!(board.index(3, 0).unwrap(), 3);
+        assert_eq!(board.index(0, 1).unwrap(), 4);
+        assert_eq!(board.index(3, 2).unwrap(), 11);
+
+        board.index(4, 0).expect_err("x out of bounds");
+        board.index(0, 3).expect_err("y out of bounds");
+    }
+
+    #[test]
+    fn board_tracks_occupancy_and_clears_stale_cells_on_move() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 5, y: 5 });
+
+        assert_eq!(game.board.tank_at(Position { x: 0, y: 0 }), Some(1));
+
+        game.make_move(1, Direction::Right).unwrap();
+
+        assert_eq!(game.board.tank_at(Position { x: 0, y: 0 }), None);
+        assert_eq!(game.board.tank_at(Position { x: 1, y: 0 }), Some(1));
+    }
+
+    #[test]
+    fn board_clears_cell_when_tank_is_destroyed() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.get_tank_mut(2).unwrap().life = 1;
+
+        assert_eq!(game.board.tank_at(Position { x: 1, y: 1 }), Some(2));
+
+        game.shoot(1, 2).unwrap();
+
+        assert_eq!(game.board.tank_at(Position { x: 1, y: 1 }), None);
+    }
+
+    #[test]
+    fn render_draws_a_bordered_grid_with_tank_glyphs() {
+        let game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 2, y: 1 });
+
+        let rendered = game.render();
+        let grid: Vec<&str> = rendered.lines().take(10).collect();
+
+        assert_eq!(grid[0], "+--------+");
+        assert_eq!(grid.last().unwrap().len(), 10);
+        assert_eq!(grid[1].chars().nth(1).unwrap(), 'B');
+        assert_eq!(grid[2].chars().nth(3).unwrap(), 'R');
+        assert!(rendered.contains("Attacker"));
+        assert!(rendered.contains("Target"));
+    }
+
+    #[test]
+    fn action_from_str_parses_explicit_wire_commands() {
+        assert_eq!(
+            "move 3 up".parse::<Action>().unwrap(),
+            Action::Move {
+                tank: 3,
+                direction: Direction::Up,
+            }
+        );
+        assert_eq!(
+            "shoot 3 4".parse::<Action>().unwrap(),
+            Action::Shoot {
+                attacker: 3,
+                target: 4,
+            }
+        );
+        assert_eq!(
+            "gift 5 2 1".parse::<Action>().unwrap(),
+            Action::Gift {
+                from: 5,
+                to: 2,
+                amount: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn action_parse_rejects_malformed_input() {
+        "".parse::<Action>().expect_err("empty input should fail");
+        "teleport 1 2".parse::<Action>().expect_err("unknown verb should fail");
+        "move up".parse::<Action>().expect_err("move requires a tank id");
+        "shoot 3".parse::<Action>().expect_err("shoot requires both ids");
+        "shoot three 4".parse::<Action>().expect_err("ids must be numeric");
+    }
+
+    #[test]
+    fn action_from_chat_parses_short_commands_for_the_actor() {
+        assert_eq!(
+            Action::from_chat("move up", 3).unwrap(),
+            Action::Move {
+                tank: 3,
+                direction: Direction::Up,
+            }
+        );
+        assert_eq!(
+            Action::from_chat("shoot 3 4", 3).unwrap(),
+            Action::Shoot {
+                attacker: 3,
+                target: 4,
+            }
+        );
+        assert_eq!(
+            Action::from_chat("give 5 2", 7).unwrap(),
+            Action::Gift {
+                from: 7,
+                to: 5,
+                amount: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn action_from_chat_rejects_malformed_input() {
+        Action::from_chat("", 1).expect_err("empty input should fail");
+        Action::from_chat("teleport 1 2", 1).expect_err("unknown verb should fail");
+        Action::from_chat("move", 1).expect_err("move requires a direction");
+        Action::from_chat("shoot 3", 1).expect_err("shoot requires both ids");
+        Action::from_chat("give 5", 1).expect_err("gift requires an amount");
+    }
+
+    #[test]
+    fn action_display_and_from_str_round_trip() {
+        let actions = [
+            Action::Move {
+                tank: 7,
+                direction: Direction::Left,
+            },
+            Action::Shoot {
+                attacker: 1,
+                target: 2,
+            },
+            Action::Gift {
+                from: 4,
+                to: 9,
+                amount: 3,
+            },
+        ];
+
+        for action in actions {
+            let wire = action.to_string();
+            assert_eq!(wire.parse::<Action>().unwrap(), action);
+        }
+    }
+
+    #[test]
+    fn apply_dispatches_to_the_underlying_mutation() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+
+        let result = game.apply(Action::Move {
+            tank: 1,
+            direction: Direction::Right,
+        });
+        assert_eq!(result.unwrap(), ActionResult::Moved(Position { x: 1, y: 0 }));
+    }
+
+    #[test]
+    fn apply_chat_parses_and_dispatches_on_behalf_of_the_actor() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+
+        let result = game.apply_chat("move right", 1);
+        assert_eq!(result.unwrap(), ActionResult::Moved(Position { x: 1, y: 0 }));
+    }
+
+    #[test]
+    fn gift_insufficient_points_expects_error() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.get_tank_mut(1).unwrap().action_points = 0;
+
+        game.gift(1, 2, 1)
+            .expect_err("Gifting more points than owned should be rejected");
+        assert_eq!(game.get_tank(2).unwrap().action_points, 2);
+    }
+
+    #[test]
+    fn gift_out_of_range_expects_error() {
+        let mut game = shooter_and_target(1, Position { x: 0, y: 0 }, Position { x: 5, y: 5 });
+        game.get_tank_mut(1).unwrap().action_points = 3;
+
+        game.gift(1, 2, 1)
+            .expect_err("Gifting to a tank out of range should be rejected");
+        assert_eq!(game.get_tank(2).unwrap().action_points, 2);
+    }
+
+    #[test]
+    fn gift_transfers_action_points_between_allies() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.get_tank_mut(2).unwrap().team = Team::Blue;
+        game.get_tank_mut(1).unwrap().action_points = 3;
+
+        game.gift(1, 2, 2).unwrap();
+
+        assert_eq!(game.get_tank(1).unwrap().action_points, 1);
+        assert_eq!(game.get_tank(2).unwrap().action_points, 4);
+    }
+
+    #[test]
+    fn gift_saturates_recipient_action_points_at_max() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.get_tank_mut(1).unwrap().action_points = u8::MAX;
+        game.get_tank_mut(2).unwrap().action_points = u8::MAX - 1;
+
+        game.gift(1, 2, 5).unwrap();
+
+        assert_eq!(game.get_tank(2).unwrap().action_points, u8::MAX);
+    }
+
+    #[test]
+    fn tick_grants_points_after_multiple_elapsed_intervals() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.settings = Settings {
+            ap_per_tick: 2,
+            tick_interval_secs: 100,
+        };
+        game.last_tick = 0;
+        game.get_tank_mut(1).unwrap().action_points = 0;
+        game.get_tank_mut(2).unwrap().action_points = 0;
+
+        let report = game.tick(250);
+
+        assert_eq!(report.ticks_applied, 2);
+        assert_eq!(game.get_tank(1).unwrap().action_points, 4);
+        assert_eq!(game.get_tank(2).unwrap().action_points, 4);
+        assert_eq!(game.last_tick, 200);
+    }
+
+    #[test]
+    fn tick_is_a_no_op_before_an_interval_elapses() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.settings = Settings {
+            ap_per_tick: 2,
+            tick_interval_secs: 100,
+        };
+        game.last_tick = 0;
+        game.get_tank_mut(1).unwrap().action_points = 0;
+
+        let report = game.tick(99);
+
+        assert_eq!(report.ticks_applied, 0);
+        assert_eq!(game.get_tank(1).unwrap().action_points, 0);
+        assert_eq!(game.last_tick, 0);
+    }
+
+    #[test]
+    fn tick_does_not_grant_points_to_destroyed_tanks() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.settings = Settings {
+            ap_per_tick: 1,
+            tick_interval_secs: 100,
+        };
+        game.last_tick = 0;
+        game.get_tank_mut(2).unwrap().destroyed = true;
+        game.get_tank_mut(2).unwrap().action_points = 0;
+
+        game.tick(100);
+
+        assert_eq!(game.get_tank(2).unwrap().action_points, 0);
+    }
+
+    #[test]
+    fn is_stale_reports_games_idle_past_the_threshold() {
+        let mut game = shooter_and_target(3, Position { x: 0, y: 0 }, Position { x: 1, y: 1 });
+        game.last_tick = 0;
+
+        assert!(!game.is_stale(499, 500));
+        assert!(game.is_stale(500, 500));
+    }
 }
@@ -0,0 +1,346 @@
+use std::time::{Duration, Instant};
+
+use rand::seq::IndexedRandom;
+
+use crate::server::{Action, Direction, Game, Team};
+
+const EXPLORATION_CONSTANT: f64 = std::f64::consts::SQRT_2;
+const MAX_ROLLOUT_DEPTH: u32 = 40;
+
+struct Node {
+    parent: Option<usize>,
+    action: Option<Action>,
+    acting_tank: u32,
+    children: Vec<usize>,
+    visits: u32,
+    value: f64,
+    untried_actions: Vec<Action>,
+}
+
+pub struct Mcts<'a> {
+    root_game: &'a Game,
+    planning_team: Team,
+    nodes: Vec<Node>,
+}
+
+impl<'a> Mcts<'a> {
+    pub fn search(root_game: &'a Game, tank_id: u32, time_budget: Duration) -> Option<Action> {
+        let planning_team = root_game.get_tank(tank_id)?.team;
+        let root_actions = legal_actions(root_game, tank_id);
+        if root_actions.is_empty() {
+            return None;
+        }
+
+        let mut mcts = Mcts {
+            root_game,
+            planning_team,
+            nodes: vec![Node {
+                parent: None,
+                action: None,
+                acting_tank: tank_id,
+                children: Vec::new(),
+                visits: 0,
+                value: 0.0,
+                untried_actions: root_actions,
+            }],
+        };
+
+        let deadline = Instant::now() + time_budget;
+        while Instant::now() < deadline {
+            mcts.run_iteration();
+        }
+
+        mcts.best_action()
+    }
+
+    fn best_action(&self) -> Option<Action> {
+        self.nodes[0]
+            .children
+            .iter()
+            .max_by_key(|&&idx| self.nodes[idx].visits)
+            .and_then(|&idx| self.nodes[idx].action)
+    }
+
+    fn run_iteration(&mut self) {
+        let mut node_idx = self.select();
+        let mut game = self.replay(node_idx);
+        let mut acting_tank = self.nodes[node_idx].acting_tank;
+
+        if game.check_winner().is_none() && !self.nodes[node_idx].untried_actions.is_empty() {
+            node_idx = self.expand(node_idx, &game);
+            game = self.replay(node_idx);
+            acting_tank = self.nodes[node_idx].acting_tank;
+        }
+
+        let result = self.rollout(game, acting_tank);
+        self.backpropagate(node_idx, result);
+    }
+
+    fn select(&self) -> usize {
+        let mut node_idx = 0;
+        loop {
+            let node = &self.nodes[node_idx];
+            if !node.untried_actions.is_empty() || node.children.is_empty() {
+                return node_idx;
+            }
+            node_idx = self.best_uct_child(node_idx);
+        }
+    }
+
+    fn best_uct_child(&self, node_idx: usize) -> usize {
+        let parent_visits = self.nodes[node_idx].visits as f64;
+        *self.nodes[node_idx]
+            .children
+            .iter()
+            .max_by(|&&a, &&b| {
+                self.uct(a, parent_visits)
+                    .partial_cmp(&self.uct(b, parent_visits))
+                    .unwrap()
+            })
+            .unwrap()
+    }
+
+    fn uct(&self, node_idx: usize, parent_visits: f64) -> f64 {
+        let node = &self.nodes[node_idx];
+        if node.visits == 0 {
+            return f64::INFINITY;
+        }
+        let mean = node.value / node.visits as f64;
+        mean + EXPLORATION_CONSTANT * (parent_visits.ln() / node.visits as f64).sqrt()
+    }
+
+    fn expand(&mut self, node_idx: usize, game: &Game) -> usize {
+        let action = self.nodes[node_idx].untried_actions.pop().unwrap();
+
+        let mut next_game = game.clone();
+        let _ = next_game.apply(action);
+
+        let acting_tank = self.nodes[node_idx].acting_tank;
+        let next_tank = next_acting_tank(&next_game, acting_tank).unwrap_or(acting_tank);
+        let untried_actions = legal_actions(&next_game, next_tank);
+
+        let child_idx = self.nodes.len();
+        self.nodes.push(Node {
+            parent: Some(node_idx),
+            action: Some(action),
+            acting_tank: next_tank,
+            children: Vec::new(),
+            visits: 0,
+            value: 0.0,
+            untried_actions,
+        });
+        self.nodes[node_idx].children.push(child_idx);
+
+        child_idx
+    }
+
+    fn rollout(&self, mut game: Game, mut acting_tank: u32) -> f64 {
+        for _ in 0..MAX_ROLLOUT_DEPTH {
+            if let Some(winner) = game.check_winner() {
+                return self.score(winner);
+            }
+
+            let actions = legal_actions(&game, acting_tank);
+            if let Some(&action) = actions.choose(&mut rand::rng()) {
+                let _ = game.apply(action);
+            }
+
+            acting_tank = next_acting_tank(&game, acting_tank).unwrap_or(acting_tank);
+        }
+
+        match game.check_winner() {
+            Some(winner) => self.score(winner),
+            None => 0.0,
+        }
+    }
+
+    fn score(&self, winner: Team) -> f64 {
+        if winner == self.planning_team {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn backpropagate(&mut self, node_idx: usize, result: f64) {
+        let mut current = Some(node_idx);
+        while let Some(idx) = current {
+            let node = &mut self.nodes[idx];
+            node.visits += 1;
+            node.value += result;
+            current = node.parent;
+        }
+    }
+
+    fn path_actions(&self, node_idx: usize) -> Vec<Action> {
+        let mut path = Vec::new();
+        let mut current = node_idx;
+        while let Some(action) = self.nodes[current].action {
+            path.push(action);
+            current = self.nodes[current].parent.unwrap();
+        }
+        path.reverse();
+        path
+    }
+
+    fn replay(&self, node_idx: usize) -> Game {
+        let mut game = self.root_game.clone();
+        for action in self.path_actions(node_idx) {
+            let _ = game.apply(action);
+        }
+        game
+    }
+}
+
+fn next_acting_tank(game: &Game, current: u32) -> Option<u32> {
+    let mut ids: Vec<u32> = game
+        .tanks()
+        .iter()
+        .filter(|tank| !tank.destroyed)
+        .map(|tank| tank.id)
+        .collect();
+    if ids.is_empty() {
+        return None;
+    }
+    ids.sort_unstable();
+    Some(match ids.iter().find(|&&id| id > current) {
+        Some(&id) => id,
+        None => ids[0],
+    })
+}
+
+fn legal_actions(game: &Game, tank_id: u32) -> Vec<Action> {
+    let mut actions = Vec::new();
+
+    let Some(tank) = game.get_tank(tank_id) else {
+        return actions;
+    };
+    if tank.destroyed {
+        return actions;
+    }
+
+    for direction in [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ] {
+        let mut trial = game.clone();
+        if trial
+            .apply(Action::Move {
+                tank: tank_id,
+                direction,
+            })
+            .is_ok()
+        {
+            actions.push(Action::Move {
+                tank: tank_id,
+                direction,
+            });
+        }
+    }
+
+    if tank.action_points >= 1 {
+        for other in game.tanks() {
+            if other.destroyed || other.id == tank_id {
+                continue;
+            }
+            let dx = tank.position.x.abs_diff(other.position.x);
+            let dy = tank.position.y.abs_diff(other.position.y);
+            if dx.max(dy) > tank.range as u32 {
+                continue;
+            }
+            if other.team == tank.team {
+                actions.push(Action::Gift {
+                    from: tank_id,
+                    to: other.id,
+                    amount: 1,
+                });
+            } else {
+                actions.push(Action::Shoot {
+                    attacker: tank_id,
+                    target: other.id,
+                });
+            }
+        }
+    }
+
+    actions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{Board, Position, Tank, Team};
+
+    #[test]
+    fn best_action_picks_the_only_legal_move() {
+        let board = Board::new(2, 1);
+        let attacker = Tank::new(
+            3,
+            1,
+            Team::Blue,
+            1,
+            Position { x: 0, y: 0 },
+            String::from("Attacker"),
+            1,
+        );
+        let target = Tank::new(
+            1,
+            1,
+            Team::Red,
+            1,
+            Position { x: 1, y: 0 },
+            String::from("Target"),
+            2,
+        );
+
+        let mut game = Game::new_lobby(1, board, 0);
+        game.add_tank(attacker).unwrap();
+        game.add_tank(target).unwrap();
+        game.start().unwrap();
+
+        let action = game
+            .best_action(1, Duration::from_millis(20))
+            .expect("a cornered tank with one enemy in range has exactly one legal action");
+
+        assert_eq!(
+            action,
+            Action::Shoot {
+                attacker: 1,
+                target: 2
+            }
+        );
+    }
+
+    #[test]
+    fn best_action_returns_none_for_a_destroyed_tank() {
+        let board = Board::new(4, 4);
+        let attacker = Tank::new(
+            0,
+            1,
+            Team::Blue,
+            1,
+            Position { x: 0, y: 0 },
+            String::from("Attacker"),
+            1,
+        );
+        let target = Tank::new(
+            1,
+            1,
+            Team::Red,
+            1,
+            Position { x: 1, y: 0 },
+            String::from("Target"),
+            2,
+        );
+
+        let mut game = Game::new_lobby(1, board, 0);
+        game.add_tank(attacker).unwrap();
+        game.add_tank(target).unwrap();
+        game.start().unwrap();
+        game.get_tank_mut(1).unwrap().destroyed = true;
+
+        assert_eq!(game.best_action(1, Duration::from_millis(20)), None);
+    }
+}